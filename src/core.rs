@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use crate::neighbors::Direction;
 use crate::{Coordinate, GeohashError, Neighbors, Rect};
 
@@ -6,33 +8,64 @@ static BASE32_CODES: &[char] = &[
     'm', 'n', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
 ];
 
-/// Encode a coordinate to a geohash with length `len`.
-///
-/// ### Examples
-///
-/// Encoding a coordinate to a length five geohash:
-///
-/// ```rust
-/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
-///
-/// let geohash_string = geohash::encode(coord, 5).expect("Invalid coordinate");
-///
-/// assert_eq!(geohash_string, "9q60y");
-/// ```
-///
-/// Encoding a coordinate to a length ten geohash:
-///
-/// ```rust
-/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
-///
-/// let geohash_string = geohash::encode(coord, 10).expect("Invalid coordinate");
-///
-/// assert_eq!(geohash_string, "9q60y60rhs");
-/// ```
-pub fn encode(c: Coordinate<f64>, len: usize) -> Result<String, GeohashError> {
+static BASE16_CODES: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
+];
+
+/// A character alphabet `encode_with`/`decode_bbox_with` chunk geohash bits
+/// into, and how many bits each character of it represents. The alphabet's
+/// length must be `2.pow(bits_per_char)` so every bit pattern maps to a
+/// character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    codes: &'static [char],
+    bits_per_char: u32,
+}
+
+impl Alphabet {
+    /// The standard 32-character geohash alphabet used by [`encode`] and
+    /// [`decode_bbox`] (5 bits per character).
+    pub const BASE32: Alphabet = Alphabet {
+        codes: BASE32_CODES,
+        bits_per_char: 5,
+    };
+
+    /// A hexadecimal alphabet (4 bits per character), coarser but more
+    /// widely recognized than base32.
+    pub const BASE16: Alphabet = Alphabet {
+        codes: BASE16_CODES,
+        bits_per_char: 4,
+    };
+
+    fn bits_per_char(&self) -> u32 {
+        self.bits_per_char
+    }
+
+    fn code(&self, value: usize) -> char {
+        self.codes[value]
+    }
+
+    /// Reverse lookup: the bit value a character of this alphabet encodes.
+    fn value_of(&self, c: char) -> Result<usize, GeohashError> {
+        self.codes
+            .iter()
+            .position(|&code| code == c)
+            .ok_or(GeohashError::InvalidHashCharacter(c))
+    }
+}
+
+/// Encode a coordinate to a geohash with length `len`, using `alphabet`'s
+/// character set and bit width instead of the standard base32 one. See
+/// [`encode`].
+pub fn encode_with(
+    c: Coordinate<f64>,
+    len: usize,
+    alphabet: Alphabet,
+) -> Result<String, GeohashError> {
     let mut out = String::with_capacity(len);
 
-    let mut bits_total: i8 = 0;
+    let bits_per_char = alphabet.bits_per_char();
+    let mut bits_total: u32 = 0;
     let mut hash_value: usize = 0;
     let mut max_lat = 90f64;
     let mut min_lat = -90f64;
@@ -44,7 +77,7 @@ pub fn encode(c: Coordinate<f64>, len: usize) -> Result<String, GeohashError> {
     }
 
     while out.len() < len {
-        for _ in 0..5 {
+        for _ in 0..bits_per_char {
             if bits_total % 2 == 0 {
                 let mid = (max_lon + min_lon) / 2f64;
                 if c.x > mid {
@@ -67,38 +100,165 @@ pub fn encode(c: Coordinate<f64>, len: usize) -> Result<String, GeohashError> {
             bits_total += 1;
         }
 
-        let code: char = BASE32_CODES[hash_value];
-        out.push(code);
+        out.push(alphabet.code(hash_value));
         hash_value = 0;
     }
     Ok(out)
 }
 
-/// Decode geohash string into latitude, longitude
+/// Encode a coordinate to a geohash with length `len`.
 ///
-/// Parameters:
-/// Geohash encoded `&str`
+/// ### Examples
 ///
-/// Returns:
-/// A four-element tuple describs a bound box:
-/// * min_lat
-/// * max_lat
-/// * min_lon
-/// * max_lon
-pub fn decode_bbox(hash_str: &str) -> Result<Rect<f64>, GeohashError> {
+/// Encoding a coordinate to a length five geohash:
+///
+/// ```rust
+/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
+///
+/// let geohash_string = geohash::encode(coord, 5).expect("Invalid coordinate");
+///
+/// assert_eq!(geohash_string, "9q60y");
+/// ```
+///
+/// Encoding a coordinate to a length ten geohash:
+///
+/// ```rust
+/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
+///
+/// let geohash_string = geohash::encode(coord, 10).expect("Invalid coordinate");
+///
+/// assert_eq!(geohash_string, "9q60y60rhs");
+/// ```
+pub fn encode(c: Coordinate<f64>, len: usize) -> Result<String, GeohashError> {
+    encode_with(c, len, Alphabet::BASE32)
+}
+
+/// The largest `bits_per_dim` accepted by [`encode_int`]/[`decode_bbox_int`].
+/// 32 bits per dimension gives 64 bits total, the common integer geohash
+/// precision (e.g. Redis `GEOADD`).
+const MAX_BITS_PER_DIM: u8 = 32;
+
+/// Encode a coordinate into a 64-bit integer geohash with `bits_per_dim` bits
+/// of precision per dimension (so `2 * bits_per_dim` bits are used in total).
+///
+/// This is the same bisection used by [`encode`], but the longitude/latitude
+/// bits are interleaved into a `u64` instead of grouped into base32
+/// characters, which makes the result usable as a sortable key (e.g. for
+/// range scans in a sorted set).
+///
+/// ### Examples
+///
+/// ```rust
+/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
+///
+/// let hash = geohash::encode_int(coord, 26).expect("Invalid coordinate");
+/// let rect = geohash::decode_bbox_int(hash, 26).expect("Invalid hash");
+///
+/// assert!(rect.min().x <= coord.x && coord.x <= rect.max().x);
+/// assert!(rect.min().y <= coord.y && coord.y <= rect.max().y);
+/// ```
+pub fn encode_int(c: Coordinate<f64>, bits_per_dim: u8) -> Result<u64, GeohashError> {
+    let mut max_lat = 90f64;
+    let mut min_lat = -90f64;
+    let mut max_lon = 180f64;
+    let mut min_lon = -180f64;
+
+    if c.x < min_lon || c.x > max_lon || c.y < min_lat || c.y > max_lat {
+        return Err(GeohashError::InvalidCoordinateRange(c));
+    }
+    if bits_per_dim == 0 || bits_per_dim > MAX_BITS_PER_DIM {
+        return Err(GeohashError::InvalidBitsPerDim(bits_per_dim));
+    }
+
+    let mut hash_value: u64 = 0;
+    for i in 0..(bits_per_dim as u32 * 2) {
+        if i % 2 == 0 {
+            let mid = (max_lon + min_lon) / 2f64;
+            if c.x > mid {
+                hash_value = (hash_value << 1) + 1;
+                min_lon = mid;
+            } else {
+                hash_value <<= 1;
+                max_lon = mid;
+            }
+        } else {
+            let mid = (max_lat + min_lat) / 2f64;
+            if c.y > mid {
+                hash_value = (hash_value << 1) + 1;
+                min_lat = mid;
+            } else {
+                hash_value <<= 1;
+                max_lat = mid;
+            }
+        }
+    }
+
+    Ok(hash_value)
+}
+
+/// Decode a 64-bit integer geohash produced by [`encode_int`] back into its
+/// bounding box, given the same `bits_per_dim` used to encode it.
+pub fn decode_bbox_int(hash: u64, bits_per_dim: u8) -> Result<Rect<f64>, GeohashError> {
+    if bits_per_dim == 0 || bits_per_dim > MAX_BITS_PER_DIM {
+        return Err(GeohashError::InvalidBitsPerDim(bits_per_dim));
+    }
+
+    let mut is_lon = true;
+    let mut max_lat = 90f64;
+    let mut min_lat = -90f64;
+    let mut max_lon = 180f64;
+    let mut min_lon = -180f64;
+    let mut mid: f64;
+
+    for bs in (0..(bits_per_dim as u32 * 2)).rev() {
+        let bit = (hash >> bs) & 1;
+        if is_lon {
+            mid = (max_lon + min_lon) / 2f64;
+            if bit == 1 {
+                min_lon = mid;
+            } else {
+                max_lon = mid;
+            }
+        } else {
+            mid = (max_lat + min_lat) / 2f64;
+            if bit == 1 {
+                min_lat = mid;
+            } else {
+                max_lat = mid;
+            }
+        }
+        is_lon = !is_lon;
+    }
+
+    Ok(Rect::new(
+        Coordinate {
+            x: min_lon,
+            y: min_lat,
+        },
+        Coordinate {
+            x: max_lon,
+            y: max_lat,
+        },
+    ))
+}
+
+/// Decode a geohash string into its bounding box, using `alphabet`'s
+/// character set and bit width instead of the standard base32 one. See
+/// [`decode_bbox`].
+pub fn decode_bbox_with(hash_str: &str, alphabet: Alphabet) -> Result<Rect<f64>, GeohashError> {
+    let bits_per_char = alphabet.bits_per_char();
     let mut is_lon = true;
     let mut max_lat = 90f64;
     let mut min_lat = -90f64;
     let mut max_lon = 180f64;
     let mut min_lon = -180f64;
     let mut mid: f64;
-    let mut hash_value: usize;
 
     for c in hash_str.chars() {
-        hash_value = hash_value_of_char(c)?;
+        let hash_value = alphabet.value_of(c)?;
 
-        for bs in 0..5 {
-            let bit = (hash_value >> (4 - bs)) & 1usize;
+        for bs in 0..bits_per_char {
+            let bit = (hash_value >> (bits_per_char - 1 - bs)) & 1usize;
             if is_lon {
                 mid = (max_lon + min_lon) / 2f64;
 
@@ -132,20 +292,16 @@ pub fn decode_bbox(hash_str: &str) -> Result<Rect<f64>, GeohashError> {
     ))
 }
 
-fn hash_value_of_char(c: char) -> Result<usize, GeohashError> {
-    let ord = c as usize;
-    if (48..=57).contains(&ord) {
-        return Ok(ord - 48);
-    } else if (98..=104).contains(&ord) {
-        return Ok(ord - 88);
-    } else if (106..=107).contains(&ord) {
-        return Ok(ord - 89);
-    } else if (109..=110).contains(&ord) {
-        return Ok(ord - 90);
-    } else if (112..=122).contains(&ord) {
-        return Ok(ord - 91);
-    }
-    Err(GeohashError::InvalidHashCharacter(c))
+/// Decode geohash string into latitude, longitude
+///
+/// Parameters:
+/// Geohash encoded `&str`
+///
+/// Returns:
+/// A `Rect` describing the bounding box of the geohash cell, with
+/// `min()` at (min_lon, min_lat) and `max()` at (max_lon, max_lat).
+pub fn decode_bbox(hash_str: &str) -> Result<Rect<f64>, GeohashError> {
+    decode_bbox_with(hash_str, Alphabet::BASE32)
 }
 
 /// Decode a geohash into a coordinate with some longitude/latitude error. The
@@ -206,15 +362,130 @@ pub fn decode(hash_str: &str) -> Result<(Coordinate<f64>, f64, f64), GeohashErro
     ))
 }
 
-/// Find neighboring geohashes for the given geohash and direction.
-pub fn neighbor(hash_str: &str, direction: Direction) -> Result<String, GeohashError> {
+/// Rough meters per degree at the equator, used to convert geohash cell
+/// sizes (in degrees) to meters for [`search_radius`].
+const METERS_PER_DEGREE: f64 = 111_320f64;
+
+/// Smallest precision considered by [`search_radius`], used as a floor near
+/// the poles where `cos(lat)` collapses the east/west cell width to zero.
+const MIN_RADIUS_PRECISION: usize = 1;
+
+/// Largest precision considered by [`search_radius`], so a tiny radius
+/// doesn't walk all the way out to the length limit of a geohash string.
+const MAX_RADIUS_PRECISION: usize = 12;
+
+/// Find the geohash cells covering a circle of `radius_meters` around
+/// `center`: the largest geohash precision whose cells are each at least as
+/// wide and tall as the radius, that cell's 8 neighbors, and the center hash
+/// itself. Together the center cell and its neighbors form a 3x3 block that
+/// fully covers the circle, letting callers do `GEORADIUS`-style proximity
+/// filtering without a spatial database.
+///
+/// When even a length-1 cell is narrower or shorter than the radius (a very
+/// large radius, or a center near a pole), precision 1 is returned anyway,
+/// and the block then does not necessarily cover the whole circle.
+pub fn search_radius(
+    center: Coordinate<f64>,
+    radius_meters: f64,
+) -> Result<(usize, Neighbors, String), GeohashError> {
+    let mut precision = MIN_RADIUS_PRECISION;
+
+    for n in MIN_RADIUS_PRECISION..=MAX_RADIUS_PRECISION {
+        let lon_bits = (5 * n as u32 + 1) / 2; // ceil(5n/2)
+        let lat_bits = 5 * n as u32 / 2; // floor(5n/2)
+
+        let cell_width_deg = 360f64 / 2f64.powi(lon_bits as i32);
+        let cell_height_deg = 180f64 / 2f64.powi(lat_bits as i32);
+
+        // Near the poles cos(lat) approaches zero, which makes every cell
+        // look arbitrarily narrow; clamp it to a small positive value, so
+        // the search stops at the first precision and falls back to the
+        // minimum precision.
+        let lat_scale = center.y.to_radians().cos().max(f64::EPSILON);
+        let cell_width_m = cell_width_deg * lat_scale * METERS_PER_DEGREE;
+        let cell_height_m = cell_height_deg * METERS_PER_DEGREE;
+
+        if cell_width_m < radius_meters || cell_height_m < radius_meters {
+            break;
+        }
+        precision = n;
+    }
+
+    let hash = encode(center, precision)?;
+    let block = neighbors(&hash)?;
+
+    Ok((precision, block, hash))
+}
+
+/// Mean Earth radius in meters, as used by [`haversine_distance`].
+const EARTH_RADIUS_METERS: f64 = 6_371_000f64;
+
+/// Great-circle distance between two coordinates, in meters, using the
+/// haversine formula. This pairs with [`search_radius`], which returns
+/// candidate cells to prune down to an exact radius with this function.
+///
+/// ### Examples
+///
+/// ```rust
+/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
+///
+/// assert_eq!(geohash::haversine_distance(coord, coord), 0f64);
+/// ```
+pub fn haversine_distance(a: Coordinate<f64>, b: Coordinate<f64>) -> f64 {
+    let lat_a = a.y.to_radians();
+    let lat_b = b.y.to_radians();
+    let dlat = (b.y - a.y).to_radians();
+    let dlon = (b.x - a.x).to_radians();
+
+    let h = (dlat / 2f64).sin().powi(2) + lat_a.cos() * lat_b.cos() * (dlon / 2f64).sin().powi(2);
+
+    2f64 * EARTH_RADIUS_METERS * h.sqrt().min(1f64).asin()
+}
+
+/// Decode two geohashes and return the great-circle distance between their
+/// center points, in meters. See [`haversine_distance`].
+pub fn distance_between(hash_a: &str, hash_b: &str) -> Result<f64, GeohashError> {
+    let (coord_a, _, _) = decode(hash_a)?;
+    let (coord_b, _, _) = decode(hash_b)?;
+    Ok(haversine_distance(coord_a, coord_b))
+}
+
+/// Normalize a longitude into `[-180, 180)` by wrapping around the
+/// antimeridian, instead of treating a crossing as an out-of-range error.
+fn wrap_longitude(lon: f64) -> f64 {
+    let wrapped = (lon + 180f64).rem_euclid(360f64) - 180f64;
+    // `rem_euclid` can round up to exactly 360 for inputs just below a
+    // multiple of 360, giving +180; map that back to -180 to keep the
+    // canonical [-180, 180) representation.
+    if wrapped >= 180f64 {
+        wrapped - 360f64
+    } else {
+        wrapped
+    }
+}
+
+/// Find the neighboring geohash for the given geohash and direction, or
+/// `None` if that direction has no neighbor.
+///
+/// Longitude wraps around the antimeridian, so a cell at the eastern edge of
+/// +180° correctly neighbors the western edge at -180°. Latitude does not
+/// wrap: a cell on the northern edge has no northern neighbor, which is
+/// represented as `None` rather than silently clamping or duplicating the
+/// center cell.
+pub fn neighbor(hash_str: &str, direction: Direction) -> Result<Option<String>, GeohashError> {
     let (coord, lon_err, lat_err) = decode(hash_str)?;
     let (dlat, dlng) = direction.to_tuple();
+
+    let neighbor_lat = coord.y + 2f64 * lat_err.abs() * dlat;
+    if !(-90f64..=90f64).contains(&neighbor_lat) {
+        return Ok(None);
+    }
+
     let neighbor_coord = Coordinate {
-        x: coord.x + 2f64 * lon_err.abs() * dlng,
-        y: coord.y + 2f64 * lat_err.abs() * dlat,
+        x: wrap_longitude(coord.x + 2f64 * lon_err.abs() * dlng),
+        y: neighbor_lat,
     };
-    encode(neighbor_coord, hash_str.len())
+    encode(neighbor_coord, hash_str.len()).map(Some)
 }
 
 /// Find all neighboring geohashes for the given geohash.
@@ -229,14 +500,14 @@ pub fn neighbor(hash_str: &str, direction: Direction) -> Result<String, GeohashE
 /// assert_eq!(
 ///     neighbors,
 ///     geohash::Neighbors {
-///         n: "9q60y60rht".to_owned(),
-///         ne: "9q60y60rhv".to_owned(),
-///         e: "9q60y60rhu".to_owned(),
-///         se: "9q60y60rhg".to_owned(),
-///         s: "9q60y60rhe".to_owned(),
-///         sw: "9q60y60rh7".to_owned(),
-///         w: "9q60y60rhk".to_owned(),
-///         nw: "9q60y60rhm".to_owned(),
+///         n: Some("9q60y60rht".to_owned()),
+///         ne: Some("9q60y60rhv".to_owned()),
+///         e: Some("9q60y60rhu".to_owned()),
+///         se: Some("9q60y60rhg".to_owned()),
+///         s: Some("9q60y60rhe".to_owned()),
+///         sw: Some("9q60y60rh7".to_owned()),
+///         w: Some("9q60y60rhk".to_owned()),
+///         nw: Some("9q60y60rhm".to_owned()),
 ///     }
 /// );
 /// ```
@@ -252,3 +523,131 @@ pub fn neighbors(hash_str: &str) -> Result<Neighbors, GeohashError> {
         ne: neighbor(hash_str, Direction::NE)?,
     })
 }
+
+/// Return every geohash of length `precision` that intersects `rect` — the
+/// building block for `_geoBoundingBox`-style filters and map-tile
+/// prefetching.
+///
+/// Walks a grid east from `rect`'s minimum corner using [`neighbor`] with
+/// [`Direction::E`], then north with [`Direction::N`], until the maximum
+/// corner has been passed.
+///
+/// `Rect` always normalizes its own corners, so `rect.min().x <=
+/// rect.max().x`; this cannot represent a box spanning the antimeridian
+/// (e.g. 170°E to 170°W), and such a span isn't supported here. Callers
+/// needing that should split it into two `coverage` calls themselves.
+pub fn coverage(rect: Rect<f64>, precision: usize) -> Result<Vec<String>, GeohashError> {
+    let min = rect.min();
+    let max = rect.max();
+
+    if precision == 0 || min.x == max.x || min.y == max.y {
+        return Err(GeohashError::InvalidCoordinateRange(min));
+    }
+
+    let mut hashes = Vec::new();
+    let mut row_hash = encode(min, precision)?;
+
+    loop {
+        let mut hash = row_hash.clone();
+        loop {
+            let bbox = decode_bbox(&hash)?;
+            hashes.push(hash.clone());
+            if bbox.max().x >= max.x {
+                break;
+            }
+            hash = match neighbor(&hash, Direction::E)? {
+                Some(h) => h,
+                None => break,
+            };
+        }
+
+        let row_bbox = decode_bbox(&row_hash)?;
+        if row_bbox.max().y >= max.y {
+            break;
+        }
+        row_hash = match neighbor(&row_hash, Direction::N)? {
+            Some(h) => h,
+            None => break,
+        };
+    }
+
+    Ok(hashes)
+}
+
+/// Like [`coverage`], but merges groups of 32 sibling cells that share a
+/// common prefix back into that shorter prefix, shrinking the result when a
+/// large area is fully covered at a coarser precision.
+///
+/// `coverage` returns hashes all of the same length, but merging a level
+/// down can leave shorter and not-yet-merged longer hashes mixed together;
+/// each pass below only looks at (and slices) the hashes still at the
+/// current level, so a shorter entry from an earlier pass is never sliced
+/// again.
+///
+/// ### Examples
+///
+/// A rect that fully tiles the 32 children of a single-character cell
+/// collapses back down to that parent hash:
+///
+/// ```rust
+/// let parent = geohash::decode_bbox("9").expect("Invalid hash");
+///
+/// // Nudge just inside the parent cell so every corner unambiguously
+/// // belongs to it rather than a neighboring cell.
+/// let rect = geohash::Rect::new(
+///     geohash::Coordinate { x: parent.min().x + 1e-6, y: parent.min().y + 1e-6 },
+///     geohash::Coordinate { x: parent.max().x - 1e-6, y: parent.max().y - 1e-6 },
+/// );
+///
+/// let hashes = geohash::largest_prefix_coverage(rect, 2).expect("Invalid rect");
+///
+/// assert_eq!(hashes, vec!["9".to_owned()]);
+/// ```
+///
+/// A rect that doesn't fill a whole parent cell is returned unmerged:
+///
+/// ```rust
+/// let coord = geohash::Coordinate { x: -120.6623, y: 35.3003 };
+/// let rect = geohash::Rect::new(
+///     coord,
+///     geohash::Coordinate { x: coord.x + 0.01, y: coord.y + 0.01 },
+/// );
+///
+/// let hashes = geohash::largest_prefix_coverage(rect, 5).expect("Invalid rect");
+///
+/// assert_eq!(hashes, vec!["9q60y".to_owned()]);
+/// ```
+pub fn largest_prefix_coverage(
+    rect: Rect<f64>,
+    precision: usize,
+) -> Result<Vec<String>, GeohashError> {
+    let mut hashes = coverage(rect, precision)?;
+
+    for level in (2..=precision).rev() {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for hash in &hashes {
+            if hash.len() == level {
+                *counts.entry(&hash[..level - 1]).or_insert(0) += 1;
+            }
+        }
+
+        let mut merged = Vec::with_capacity(hashes.len());
+        let mut merged_prefixes = HashSet::new();
+        for hash in &hashes {
+            if hash.len() == level {
+                let prefix = &hash[..level - 1];
+                if counts[prefix] == BASE32_CODES.len() {
+                    if merged_prefixes.insert(prefix) {
+                        merged.push(prefix.to_owned());
+                    }
+                    continue;
+                }
+            }
+            merged.push(hash.clone());
+        }
+
+        hashes = merged;
+    }
+
+    Ok(hashes)
+}
@@ -0,0 +1,33 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::Coordinate;
+
+/// Errors returned when encoding, decoding, or otherwise operating on
+/// geohashes.
+#[derive(Debug, PartialEq)]
+pub enum GeohashError {
+    InvalidHashCharacter(char),
+    InvalidCoordinateRange(Coordinate<f64>),
+    InvalidBitsPerDim(u8),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeohashError::InvalidHashCharacter(ch) => {
+                write!(f, "Invalid hash character: {:?}", ch)
+            }
+            GeohashError::InvalidCoordinateRange(ref c) => {
+                write!(f, "Invalid coordinate range: {:?}", c)
+            }
+            GeohashError::InvalidBitsPerDim(bits) => write!(
+                f,
+                "Invalid bits_per_dim: {} (must be between 1 and 32)",
+                bits
+            ),
+        }
+    }
+}
+
+impl Error for GeohashError {}
@@ -0,0 +1,15 @@
+//! Rust implementation of the geohash algorithm.
+
+mod core;
+mod error;
+mod neighbors;
+
+pub use crate::core::{
+    coverage, decode, decode_bbox, decode_bbox_int, decode_bbox_with, distance_between, encode,
+    encode_int, encode_with, haversine_distance, largest_prefix_coverage, neighbor, neighbors,
+    search_radius, Alphabet,
+};
+pub use crate::error::GeohashError;
+pub use crate::neighbors::{Direction, Neighbors};
+
+pub use geo_types::{Coordinate, Rect};
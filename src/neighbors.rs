@@ -0,0 +1,44 @@
+/// The eight geohashes adjacent to a given geohash, any of which may be
+/// `None` if that direction has no neighbor (e.g. there is no cell north of
+/// one already on the northern edge).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Neighbors {
+    pub sw: Option<String>,
+    pub s: Option<String>,
+    pub se: Option<String>,
+    pub w: Option<String>,
+    pub e: Option<String>,
+    pub nw: Option<String>,
+    pub n: Option<String>,
+    pub ne: Option<String>,
+}
+
+/// A compass direction relative to a geohash cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    SW,
+    S,
+    SE,
+    W,
+    E,
+    NW,
+    N,
+    NE,
+}
+
+impl Direction {
+    /// The `(lat, lon)` unit step from a cell's center towards this
+    /// direction's neighbor.
+    pub fn to_tuple(self) -> (f64, f64) {
+        match self {
+            Direction::SW => (-1f64, -1f64),
+            Direction::S => (-1f64, 0f64),
+            Direction::SE => (-1f64, 1f64),
+            Direction::W => (0f64, -1f64),
+            Direction::E => (0f64, 1f64),
+            Direction::NW => (1f64, -1f64),
+            Direction::N => (1f64, 0f64),
+            Direction::NE => (1f64, 1f64),
+        }
+    }
+}